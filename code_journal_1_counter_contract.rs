@@ -1,13 +1,15 @@
 #[cfg(not(feature = "library"))]
 //below are the annotations for the contract
 use cosmwasm_std::entry_point; //this is the entry_point annotation imported from the comswasm_std library
-use cosmwasm_std::{to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult}; //importing the required libraries from the cosmwasm_std library which
+use cosmwasm_std::{
+    to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult,
+}; //importing the required libraries from the cosmwasm_std library which
 //will be using throughout the contract
-use cw2::set_contract_version; 
+use cw2::set_contract_version;
 
 use crate::error::ContractError;
-use crate::msg::{CountResponse, ExecuteMsg, InstantiateMsg, QueryMsg}; //Importing various types created in msg.rs file and using the structs, enums here in the contract file
-use crate::state::{State, STATE}; //Importing the state to store the information in the contract
+use crate::msg::{CountResponse, ExecuteMsg, InstantiateMsg, PollResponse, PollsResponse, QueryMsg}; //Importing various types created in msg.rs file and using the structs, enums here in the contract file
+use crate::state::{Poll, State, POLLS, STATE, VOTES}; //Importing the state to store the information in the contract
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:counter-wba";
@@ -20,11 +22,17 @@ pub fn instantiate( //instantiate method to initialize the contract with the ini
     info: MessageInfo, //MessageInfo contains the information about the message sent by the user or token valye sent
     msg: InstantiateMsg, //this is the information contains in the msg.rs file which we need to initialize the data in the contract 
 ) -> Result<Response, ContractError> {
+    //enforce the bounds on the starting count too, otherwise the contract could be instantiated
+    //already out of its own range (e.g. count: 100, max: Some(5)) and every Increment/Decrement
+    //would permanently error until an explicit Reset
+    check_bounds(msg.count, msg.min, msg.max)?;
+
     let state = State {
-        count: msg.count, 
+        count: msg.count,
         owner: info.sender.clone(), //getting from the caller info as specified above
         poll_count: 0,
-
+        min: msg.min, //optional bounds, None on either side means "no limit" on that side
+        max: msg.max,
     };
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?; //setting the contract version
     STATE.save(deps.storage, &state)?; //saving the state in the contract
@@ -42,27 +50,31 @@ pub fn execute(////once after instantiating we can execute the code, this is the
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
-    match msg {//here we are executing match statement. Based on the type of ExecuteMsg, 
-        //the following arm will be executed, for example if the ExecuteMessage contains the Increment, the first arm will be executed in this case 
+    match msg {//here we are executing match statement. Based on the type of ExecuteMsg,
+        //the following arm will be executed, for example if the ExecuteMessage contains the Increment, the first arm will be executed in this case
         //which is making call to the try_increment method
         ExecuteMsg::Increment {} => try_increment(deps),
         ExecuteMsg::Decrement {} => try_decrement(deps),
         ExecuteMsg::Reset { count } => try_reset(deps, info, count),
+        ExecuteMsg::CreatePoll { question } => try_create_poll(deps, info, question),
+        ExecuteMsg::Vote { poll_id, approve } => try_vote(deps, info, poll_id, approve),
+        ExecuteMsg::ClosePoll { poll_id } => try_close_poll(deps, info, poll_id),
+        ExecuteMsg::Operations { a, b } => try_operations(deps, a, b),
     }
 }
 
-pub fn try_increment(deps: DepsMut) -> Result<Response, ContractError> {//this is the method to increment the count and update the state of the count in the contact 
+pub fn try_increment(deps: DepsMut) -> Result<Response, ContractError> {//this is the method to increment the count and update the state of the count in the contact
     STATE.update(deps.storage, |mut state| -> Result<_, ContractError> {
-        state.count += 1;//incrementing the count
+        state.count = checked_step(state.count, 1, state.min, state.max)?;//incrementing the count, now checked instead of a raw `+=`
         Ok(state)
     })?;
 
     Ok(Response::new().add_attribute("method", "try_increment")) //returning the response with the method name
 }
 
-pub fn try_decrement(deps: DepsMut) -> Result<Response, ContractError> {//this is the method to decrement the count and update the state of the count in the contact 
+pub fn try_decrement(deps: DepsMut) -> Result<Response, ContractError> {//this is the method to decrement the count and update the state of the count in the contact
     STATE.update(deps.storage, |mut state| -> Result<_, ContractError> {
-        state.count -= 1;
+        state.count = checked_step(state.count, -1, state.min, state.max)?;
         Ok(state)
     })?;
 
@@ -75,16 +87,132 @@ pub fn try_reset(deps: DepsMut, info: MessageInfo, count: i32) -> Result<Respons
         if info.sender != state.owner {
             return Err(ContractError::Unauthorized {});
         }
+        check_bounds(count, state.min, state.max)?;
         state.count = count;//resetting the count and updating the state
         Ok(state)
     })?;
     Ok(Response::new().add_attribute("method", "reset"))
 }
 
+//applies `delta` (1 or -1) to `count` using the checked variants so a wrap/panic becomes a
+//proper ContractError, then makes sure the result still falls inside the configured bounds
+fn checked_step(count: i32, delta: i32, min: Option<i32>, max: Option<i32>) -> Result<i32, ContractError> {
+    let next = if delta.is_negative() {
+        count.checked_sub(delta.unsigned_abs() as i32).ok_or(ContractError::Underflow {})?
+    } else {
+        count.checked_add(delta).ok_or(ContractError::Overflow {})?
+    };
+    check_bounds(next, min, max)?;
+    Ok(next)
+}
+
+fn check_bounds(count: i32, min: Option<i32>, max: Option<i32>) -> Result<(), ContractError> {
+    if min.is_some_and(|min| count < min) {
+        return Err(ContractError::Underflow {});
+    }
+    if max.is_some_and(|max| count > max) {
+        return Err(ContractError::Overflow {});
+    }
+    Ok(())
+}
+
+//math-by-example: runs all six checked operations on the same pair of u128 inputs and folds
+//the add result back into state.count (still respecting min/max) so Operations stays a real
+//state mutation rather than a pure calculator
+pub fn try_operations(deps: DepsMut, a: u128, b: u128) -> Result<Response, ContractError> {
+    let sum = a.checked_add(b).ok_or(ContractError::Overflow {})?;
+    let difference = a.checked_sub(b).ok_or(ContractError::Underflow {})?;
+    let product = a.checked_mul(b).ok_or(ContractError::Overflow {})?;
+    let quotient = a.checked_div(b).ok_or(ContractError::DivideByZero {})?;
+    let remainder = a.checked_rem(b).ok_or(ContractError::DivideByZero {})?;
+    let exponent: u32 = b.try_into().map_err(|_| ContractError::ExponentOverflow {})?;
+    let power = a.checked_pow(exponent).ok_or(ContractError::ExponentOverflow {})?;
+
+    let new_count = STATE.update(deps.storage, |mut state| -> Result<_, ContractError> {
+        let sum_as_count = i32::try_from(sum).map_err(|_| ContractError::Overflow {})?;
+        check_bounds(sum_as_count, state.min, state.max)?;
+        state.count = sum_as_count;
+        Ok(state)
+    })?
+    .count;
+
+    Ok(Response::new()
+        .add_attribute("method", "try_operations")
+        .add_attribute("sum", sum.to_string())
+        .add_attribute("difference", difference.to_string())
+        .add_attribute("product", product.to_string())
+        .add_attribute("quotient", quotient.to_string())
+        .add_attribute("remainder", remainder.to_string())
+        .add_attribute("power", power.to_string())
+        .add_attribute("count", new_count.to_string()))
+}
+
+pub fn try_create_poll(deps: DepsMut, info: MessageInfo, question: String) -> Result<Response, ContractError> {//owner-gated the same way try_reset is, only the owner can open a new poll
+    let state = STATE.update(deps.storage, |mut state| -> Result<_, ContractError> {
+        if info.sender != state.owner {
+            return Err(ContractError::Unauthorized {});
+        }
+        state.poll_count += 1;//this is the unused counter getting put to work, it doubles as the next poll id
+        Ok(state)
+    })?;
+
+    let poll = Poll {
+        creator: info.sender,
+        question,
+        yes_votes: 0,
+        no_votes: 0,
+        is_open: true,
+    };
+    POLLS.save(deps.storage, state.poll_count, &poll)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "try_create_poll")
+        .add_attribute("poll_id", state.poll_count.to_string()))
+}
+
+pub fn try_vote(deps: DepsMut, info: MessageInfo, poll_id: u64, approve: bool) -> Result<Response, ContractError> {//anyone can vote once per poll, no owner check here
+    let voter_key = (info.sender.clone(), poll_id);
+    if VOTES.has(deps.storage, voter_key.clone()) {
+        return Err(ContractError::AlreadyVoted {});
+    }
+
+    let mut poll = POLLS.load(deps.storage, poll_id)?;
+    if !poll.is_open {
+        return Err(ContractError::PollClosed {});
+    }
+    if approve {
+        poll.yes_votes += 1;//tallying the vote in the matching column
+    } else {
+        poll.no_votes += 1;
+    }
+    POLLS.save(deps.storage, poll_id, &poll)?;
+    VOTES.save(deps.storage, voter_key, &approve)?;//remembering this voter so they cannot vote again
+
+    Ok(Response::new()
+        .add_attribute("method", "try_vote")
+        .add_attribute("poll_id", poll_id.to_string())
+        .add_attribute("approve", approve.to_string()))
+}
+
+pub fn try_close_poll(deps: DepsMut, info: MessageInfo, poll_id: u64) -> Result<Response, ContractError> {//only the poll's own creator can close it, not necessarily the contract owner
+    let mut poll = POLLS.load(deps.storage, poll_id)?;
+    if info.sender != poll.creator {
+        return Err(ContractError::Unauthorized {});
+    }
+    poll.is_open = false;
+    POLLS.save(deps.storage, poll_id, &poll)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "try_close_poll")
+        .add_attribute("poll_id", poll_id.to_string()))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {//this is the method to query the count value from the contract
     match msg {
         QueryMsg::GetCount {} => to_binary(&query_count(deps)?),
+        QueryMsg::GetPoll { poll_id } => to_binary(&query_poll(deps, poll_id)?),
+        QueryMsg::ListPolls {} => to_binary(&query_list_polls(deps)?),
     }
 }
 
@@ -92,3 +220,250 @@ fn query_count(deps: Deps) -> StdResult<CountResponse> {
     let state = STATE.load(deps.storage)?;
     Ok(CountResponse { count: state.count })
 }
+
+fn query_poll(deps: Deps, poll_id: u64) -> StdResult<PollResponse> {
+    let poll = POLLS.load(deps.storage, poll_id)?;
+    Ok(PollResponse {
+        poll_id,
+        creator: poll.creator,
+        question: poll.question,
+        yes_votes: poll.yes_votes,
+        no_votes: poll.no_votes,
+        is_open: poll.is_open,
+    })
+}
+
+fn query_list_polls(deps: Deps) -> StdResult<PollsResponse> {
+    let polls = POLLS
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (poll_id, poll) = item?;
+            Ok(PollResponse {
+                poll_id,
+                creator: poll.creator,
+                question: poll.question,
+                yes_votes: poll.yes_votes,
+                no_votes: poll.no_votes,
+                is_open: poll.is_open,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(PollsResponse { polls })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn voting_twice_on_the_same_poll_is_rejected() {
+        let mut deps = mock_dependencies();
+
+        let owner = "owner";
+        let voter = "voter";
+
+        let instantiate_msg = InstantiateMsg {
+            count: 0,
+            min: None,
+            max: None,
+        };
+        let info = mock_info(owner, &[]);
+        instantiate(deps.as_mut(), mock_env(), info, instantiate_msg).unwrap();
+
+        let info = mock_info(owner, &[]);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::CreatePoll {
+                question: "best language?".to_string(),
+            },
+        )
+        .unwrap();
+
+        let info = mock_info(voter, &[]);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::Vote {
+                poll_id: 1,
+                approve: true,
+            },
+        )
+        .unwrap();
+
+        let info = mock_info(voter, &[]);
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::Vote {
+                poll_id: 1,
+                approve: false,
+            },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::AlreadyVoted {});
+    }
+
+    #[test]
+    fn voting_on_a_closed_poll_is_rejected() {
+        let mut deps = mock_dependencies();
+
+        let owner = "owner";
+        let voter = "voter";
+
+        let instantiate_msg = InstantiateMsg {
+            count: 0,
+            min: None,
+            max: None,
+        };
+        let info = mock_info(owner, &[]);
+        instantiate(deps.as_mut(), mock_env(), info, instantiate_msg).unwrap();
+
+        let info = mock_info(owner, &[]);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::CreatePoll {
+                question: "best language?".to_string(),
+            },
+        )
+        .unwrap();
+
+        let info = mock_info(owner, &[]);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::ClosePoll { poll_id: 1 },
+        )
+        .unwrap();
+
+        let info = mock_info(voter, &[]);
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::Vote {
+                poll_id: 1,
+                approve: true,
+            },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::PollClosed {});
+    }
+
+    #[test]
+    fn only_the_poll_creator_can_close_it() {
+        let mut deps = mock_dependencies();
+
+        let owner = "owner";
+        let rando = "rando";
+
+        let instantiate_msg = InstantiateMsg {
+            count: 0,
+            min: None,
+            max: None,
+        };
+        let info = mock_info(owner, &[]);
+        instantiate(deps.as_mut(), mock_env(), info, instantiate_msg).unwrap();
+
+        let info = mock_info(owner, &[]);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::CreatePoll {
+                question: "best language?".to_string(),
+            },
+        )
+        .unwrap();
+
+        let info = mock_info(rando, &[]);
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::ClosePoll { poll_id: 1 },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        let info = mock_info(owner, &[]);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::ClosePoll { poll_id: 1 },
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn increment_rejects_once_it_hits_max() {
+        let mut deps = mock_dependencies();
+
+        let instantiate_msg = InstantiateMsg {
+            count: 5,
+            min: None,
+            max: Some(5),
+        };
+        let info = mock_info("owner", &[]);
+        instantiate(deps.as_mut(), mock_env(), info, instantiate_msg).unwrap();
+
+        let err = try_increment(deps.as_mut()).unwrap_err();
+        assert_eq!(err, ContractError::Overflow {});
+    }
+
+    #[test]
+    fn decrement_rejects_once_it_hits_min() {
+        let mut deps = mock_dependencies();
+
+        let instantiate_msg = InstantiateMsg {
+            count: 0,
+            min: Some(0),
+            max: None,
+        };
+        let info = mock_info("owner", &[]);
+        instantiate(deps.as_mut(), mock_env(), info, instantiate_msg).unwrap();
+
+        let err = try_decrement(deps.as_mut()).unwrap_err();
+        assert_eq!(err, ContractError::Underflow {});
+    }
+
+    #[test]
+    fn operations_rejects_division_by_zero() {
+        let mut deps = mock_dependencies();
+
+        let instantiate_msg = InstantiateMsg {
+            count: 0,
+            min: None,
+            max: None,
+        };
+        let info = mock_info("owner", &[]);
+        instantiate(deps.as_mut(), mock_env(), info, instantiate_msg).unwrap();
+
+        let err = try_operations(deps.as_mut(), 10, 0).unwrap_err();
+        assert_eq!(err, ContractError::DivideByZero {});
+    }
+
+    #[test]
+    fn instantiate_rejects_a_starting_count_outside_its_own_bounds() {
+        let mut deps = mock_dependencies();
+
+        //count: 100 with max: Some(5) would otherwise leave every Increment/Decrement
+        //permanently erroring until an explicit Reset
+        let instantiate_msg = InstantiateMsg {
+            count: 100,
+            min: None,
+            max: Some(5),
+        };
+        let info = mock_info("owner", &[]);
+        let err = instantiate(deps.as_mut(), mock_env(), info, instantiate_msg).unwrap_err();
+        assert_eq!(err, ContractError::Overflow {});
+    }
+}
@@ -4,16 +4,28 @@ use std::fmt;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_binary, Addr, Api, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Response,
-    StdResult,
-};//importing the required crates from the cosmwas_std library 
+    to_binary, Addr, Api, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, DistributionMsg, Empty,
+    Env, MessageInfo, Order, Response, StakingMsg, StdResult, Uint128,
+};//importing the required crates from the cosmwas_std library
 
 use cw1::CanExecuteResponse;
 use cw2::set_contract_version;
+use cw_utils::Expiration; //used to check whether a granted allowance has timed out yet
+use sha2::{Digest, Sha256}; //used to derive a deterministic id for a scheduled operation
 
 use crate::error::ContractError; //importing the custom contract error from the error.rs file
-use crate::msg::{AdminListResponse, ExecuteMsg, InstantiateMsg, QueryMsg}; //importing the various message types from the msg.rs file
-use crate::state::{AdminList, ADMIN_LIST};//importing the state AdminList from the state file
+use crate::msg::{
+    AdminListResponse, AllAllowancesResponse, AllowanceInfo, AllowanceResponse, ExecuteMsg,
+    InstantiateMsg, PermissionsResponse, QueryMsg, ScheduledOpResponse, ScheduledOpsResponse,
+}; //importing the various message types from the msg.rs file
+use crate::state::{
+    AdminList, Allowance, Permissions, ScheduledOp, ADMIN_LIST, ALLOWANCES, MIN_DELAY, PERMISSIONS,
+    SCHEDULED_OPS,
+};//importing the state AdminList from the state file
+
+// sane default + hard cap for the `AllAllowances` pagination, same idea as the cw-plus list queries
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:cw1-whitelist"; //assigning the name to the contract 
@@ -29,9 +41,11 @@ pub fn instantiate(
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     let cfg = AdminList { //instantiating the message by passing the admins who can execute the contract
         admins: map_validate(deps.api, &msg.admins)?,
-        mutable: msg.mutable,//set whether the admin can modify the state 
+        mutable: msg.mutable,//set whether the admin can modify the state
     };
     ADMIN_LIST.save(deps.storage, &cfg)?;//saving the list of the admins in the state of the contract
+    //min_delay of None means admins execute immediately, same as before this feature existed
+    MIN_DELAY.save(deps.storage, &msg.min_delay)?;
     Ok(Response::default())
 }
 
@@ -50,27 +64,300 @@ pub fn execute(
         ExecuteMsg::Execute { msgs } => execute_execute(deps, env, info, msgs), //this will call the execute_execute method
         ExecuteMsg::Freeze {} => execute_freeze(deps, env, info),
         ExecuteMsg::UpdateAdmins { admins } => execute_update_admins(deps, env, info, admins),
+        ExecuteMsg::IncreaseAllowance { spender, amount, expires } => {
+            execute_increase_allowance(deps, env, info, spender, amount, expires)
+        }
+        ExecuteMsg::DecreaseAllowance { spender, amount, expires } => {
+            execute_decrease_allowance(deps, env, info, spender, amount, expires)
+        }
+        ExecuteMsg::SetAllowance { spender, amount, expires } => {
+            execute_set_allowance(deps, env, info, spender, amount, expires)
+        }
+        ExecuteMsg::SetPermissions { spender, permissions } => {
+            execute_set_permissions(deps, env, info, spender, permissions)
+        }
+        ExecuteMsg::Schedule { msgs, salt } => execute_schedule(deps, env, info, msgs, salt),
+        ExecuteMsg::ExecuteScheduled { id } => execute_scheduled(deps, env, info, id),
+        ExecuteMsg::Cancel { id } => execute_cancel(deps, env, info, id),
+        ExecuteMsg::Donate {} => execute_donate(deps, env, info),
+        ExecuteMsg::AddMembers { admins } => execute_add_members(deps, env, info, admins),
+        ExecuteMsg::Leave {} => execute_leave(deps, env, info),
     }
 }
 
 pub fn execute_execute<T>(//here we are defining the execute_execute method and performing some actions by checking whether
     //the caller is in the adminlist or not below
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msgs: Vec<CosmosMsg<T>>,
 ) -> Result<Response<T>, ContractError>
 where
     T: Clone + fmt::Debug + PartialEq + JsonSchema,
 {
-    if !can_execute(deps.as_ref(), info.sender.as_ref())? {//checking whether the sender is in the adminlist or not
-        Err(ContractError::Unauthorized {})
-    } else {
+    if can_execute(deps.as_ref(), info.sender.as_ref())? {
+        //a configured min_delay makes the timelock mandatory - admins can no longer bypass it by
+        //calling Execute directly, they have to go through Schedule/ExecuteScheduled instead
+        if MIN_DELAY.load(deps.storage)?.is_some() {
+            return Err(ContractError::TimelockRequired {});
+        }
+        //full admins keep unlimited execute rights, same as before
         let res = Response::new()
             .add_messages(msgs)
             .add_attribute("action", "execute");
-        Ok(res)
+        return Ok(res);
+    }
+
+    //not an admin - this may still be a subkey with a bounded allowance, so inspect every
+    //outgoing bank send and deduct it instead of rejecting outright
+    if MIN_DELAY.load(deps.storage)?.is_some() {
+        return Err(ContractError::TimelockRequired {});
+    }
+    deduct_allowances_for_execute(deps, &env, &info.sender, &msgs)?;
+
+    let res = Response::new()
+        .add_messages(msgs)
+        .add_attribute("action", "execute")
+        .add_attribute("spender", info.sender);
+    Ok(res)
+}
+
+//walks the message batch and subtracts every BankMsg::Send amount from the sender's allowance,
+//checks staking/distribution msgs against the sender's permissions, and rejects any other
+//CosmosMsg variant
+fn deduct_allowances_for_execute<T>(
+    deps: DepsMut,
+    env: &Env,
+    sender: &Addr,
+    msgs: &[CosmosMsg<T>],
+) -> Result<(), ContractError>
+where
+    T: Clone + fmt::Debug + PartialEq + JsonSchema,
+{
+    //permissions default to all-false, so a subkey with no SetPermissions call yet can't
+    //delegate/withdraw at all - it would need an allowance to spend anything either way
+    let permissions = PERMISSIONS
+        .may_load(deps.storage, sender)?
+        .unwrap_or_default();
+    let mut allowance = None;
+
+    for msg in msgs {
+        match msg {
+            CosmosMsg::Bank(BankMsg::Send { amount, .. }) => {
+                //bank sends are only looked up (and the allowance loaded) lazily, so a subkey
+                //that only has staking permissions never needs an allowance on record
+                let allowance = allowance.get_or_insert(load_allowance(deps.as_ref(), env, sender)?);
+                deduct_coins(&mut allowance.balance, amount)?;
+            }
+            CosmosMsg::Staking(staking_msg) => check_staking_permission(staking_msg, &permissions)?,
+            CosmosMsg::Distribution(distribution_msg) => {
+                check_distribution_permission(distribution_msg, &permissions)?
+            }
+            _ => return Err(ContractError::NotAllowed {}),
+        }
+    }
+
+    if let Some(allowance) = allowance {
+        ALLOWANCES.save(deps.storage, sender, &allowance)?;
+    }
+    Ok(())
+}
+
+fn load_allowance(deps: Deps, env: &Env, sender: &Addr) -> Result<Allowance, ContractError> {
+    let allowance = ALLOWANCES
+        .may_load(deps.storage, sender)?
+        .ok_or(ContractError::NoAllowance {})?;
+    //a fully spent allowance reads the same as no allowance at all
+    if allowance.expires.is_expired(&env.block) || allowance.balance.is_empty() {
+        return Err(ContractError::NoAllowance {});
+    }
+    Ok(allowance)
+}
+
+fn check_staking_permission(msg: &StakingMsg, permissions: &Permissions) -> Result<(), ContractError> {
+    let allowed = match msg {
+        StakingMsg::Delegate { .. } => permissions.delegate,
+        StakingMsg::Undelegate { .. } => permissions.undelegate,
+        StakingMsg::Redelegate { .. } => permissions.redelegate,
+        _ => false,
+    };
+    if allowed {
+        Ok(())
+    } else {
+        Err(ContractError::NotAllowed {})
+    }
+}
+
+fn check_distribution_permission(
+    msg: &DistributionMsg,
+    permissions: &Permissions,
+) -> Result<(), ContractError> {
+    let allowed = match msg {
+        //harvesting/restaking rewards is what `withdraw` is meant to grant
+        DistributionMsg::WithdrawDelegatorReward { .. } => permissions.withdraw,
+        //redirecting the withdraw address is a way to move the admin's future rewards out to
+        //an address of the subkey's choosing - that's a fund-movement right, not a harvest
+        //right, so `withdraw` alone must never grant it
+        DistributionMsg::SetWithdrawAddress { .. } => false,
+        _ => false,
+    };
+    if allowed {
+        Ok(())
+    } else {
+        Err(ContractError::NotAllowed {})
+    }
+}
+
+//subtracts `spend` from `balance` in place, erroring if any denom would go negative -
+//mirrors the coin-vector bookkeeping cw-plus uses for its native balances
+fn deduct_coins(balance: &mut Vec<Coin>, spend: &[Coin]) -> Result<(), ContractError> {
+    for coin in spend {
+        let found = balance
+            .iter_mut()
+            .find(|b| b.denom == coin.denom)
+            .ok_or(ContractError::Underflow {})?;
+        found.amount = found
+            .amount
+            .checked_sub(coin.amount)
+            .map_err(|_| ContractError::Underflow {})?;
+    }
+    balance.retain(|c| !c.amount.is_zero());
+    Ok(())
+}
+
+pub fn execute_increase_allowance(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    spender: String,
+    amount: Coin,
+    expires: Option<Expiration>,
+) -> Result<Response, ContractError> {
+    let cfg = ADMIN_LIST.load(deps.storage)?;
+    if !cfg.is_admin(info.sender.as_ref()) {
+        return Err(ContractError::Unauthorized {});
+    }
+    //once a timelock is configured, granting a fresh allowance is itself a privileged spend
+    //that needs the same delay window - otherwise an admin can grant then have the spender
+    //drain it via Execute immediately, bypassing the timelock entirely
+    if MIN_DELAY.load(deps.storage)?.is_some() {
+        return Err(ContractError::TimelockRequired {});
+    }
+    let spender_addr = deps.api.addr_validate(&spender)?;
+
+    let allowance = ALLOWANCES.update(deps.storage, &spender_addr, |allow| -> StdResult<_> {
+        let mut allow = allow.unwrap_or_default();
+        if let Some(exp) = expires {
+            allow.expires = exp;
+        }
+        add_coin(&mut allow.balance, &amount);
+        Ok(allow)
+    })?;
+
+    Ok(Response::new()
+        .add_attribute("action", "increase_allowance")
+        .add_attribute("spender", spender)
+        .add_attribute("denom", amount.denom)
+        .add_attribute("amount", amount.amount)
+        .add_attribute("new_balance", coins_to_string(&allowance.balance)))
+}
+
+pub fn execute_decrease_allowance(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    spender: String,
+    amount: Coin,
+    expires: Option<Expiration>,
+) -> Result<Response, ContractError> {
+    let cfg = ADMIN_LIST.load(deps.storage)?;
+    if !cfg.is_admin(info.sender.as_ref()) {
+        return Err(ContractError::Unauthorized {});
     }
+    let spender_addr = deps.api.addr_validate(&spender)?;
+
+    let allowance = ALLOWANCES.update(deps.storage, &spender_addr, |allow| -> Result<_, ContractError> {
+        let mut allow = allow.ok_or(ContractError::NoAllowance {})?;
+        deduct_coins(&mut allow.balance, &[amount.clone()])?;
+        if let Some(exp) = expires {
+            allow.expires = exp;
+        }
+        Ok(allow)
+    })?;
+
+    Ok(Response::new()
+        .add_attribute("action", "decrease_allowance")
+        .add_attribute("spender", spender)
+        .add_attribute("denom", amount.denom)
+        .add_attribute("amount", amount.amount)
+        .add_attribute("new_balance", coins_to_string(&allowance.balance)))
+}
+
+//unlike increase/decrease this overwrites the whole allowance, handy for resetting a subkey
+//to a known balance instead of accumulating deltas
+pub fn execute_set_allowance(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    spender: String,
+    amount: Vec<Coin>,
+    expires: Option<Expiration>,
+) -> Result<Response, ContractError> {
+    let cfg = ADMIN_LIST.load(deps.storage)?;
+    if !cfg.is_admin(info.sender.as_ref()) {
+        return Err(ContractError::Unauthorized {});
+    }
+    //same reasoning as IncreaseAllowance - a fresh/replaced allowance is a privileged grant
+    //and must respect the timelock too
+    if MIN_DELAY.load(deps.storage)?.is_some() {
+        return Err(ContractError::TimelockRequired {});
+    }
+    let spender_addr = deps.api.addr_validate(&spender)?;
+
+    let allowance = Allowance {
+        balance: amount,
+        expires: expires.unwrap_or_default(),
+    };
+    ALLOWANCES.save(deps.storage, &spender_addr, &allowance)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_allowance")
+        .add_attribute("spender", spender)
+        .add_attribute("new_balance", coins_to_string(&allowance.balance)))
+}
+
+pub fn execute_set_permissions(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    spender: String,
+    permissions: Permissions,
+) -> Result<Response, ContractError> {
+    let cfg = ADMIN_LIST.load(deps.storage)?;
+    if !cfg.is_admin(info.sender.as_ref()) {
+        return Err(ContractError::Unauthorized {});
+    }
+    let spender_addr = deps.api.addr_validate(&spender)?;
+    PERMISSIONS.save(deps.storage, &spender_addr, &permissions)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_permissions")
+        .add_attribute("spender", spender))
+}
+
+fn add_coin(balance: &mut Vec<Coin>, coin: &Coin) {
+    match balance.iter_mut().find(|b| b.denom == coin.denom) {
+        Some(existing) => existing.amount += coin.amount,
+        None => balance.push(coin.clone()),
+    }
+}
+
+fn coins_to_string(coins: &[Coin]) -> String {
+    coins
+        .iter()
+        .map(|c| format!("{}{}", c.amount, c.denom))
+        .collect::<Vec<_>>()
+        .join(",")
 }
 
 pub fn execute_freeze(//when we want to freeze or restrict the access of the admins to the contract
@@ -110,6 +397,180 @@ pub fn execute_update_admins(
     }
 }
 
+//lets an existing admin append new addresses without having to re-send the whole list the way
+//UpdateAdmins requires - avoids the read-modify-write race where a second admin's concurrent
+//UpdateAdmins could otherwise clobber this one
+pub fn execute_add_members(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    admins: Vec<String>,
+) -> Result<Response, ContractError> {
+    let mut cfg = ADMIN_LIST.load(deps.storage)?;
+    if !cfg.can_modify(info.sender.as_ref()) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut added = vec![];
+    for admin in map_validate(deps.api, &admins)? {
+        if !cfg.admins.contains(&admin) {
+            added.push(admin.to_string());
+            cfg.admins.push(admin);
+        }
+    }
+    ADMIN_LIST.save(deps.storage, &cfg)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "add_members")
+        .add_attribute("added", added.join(",")))
+}
+
+//lets an admin remove only themselves, without submitting the remaining admins like
+//UpdateAdmins does - still rejected once the list is frozen (mutable is false)
+pub fn execute_leave(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    let mut cfg = ADMIN_LIST.load(deps.storage)?;
+    if !cfg.can_modify(info.sender.as_ref()) {
+        return Err(ContractError::Unauthorized {});
+    }
+    //the last admin leaving would permanently brick every admin-gated entry point, so refuse it
+    if cfg.admins.len() == 1 {
+        return Err(ContractError::NoAdmins {});
+    }
+
+    cfg.admins.retain(|a| a != &info.sender);
+    ADMIN_LIST.save(deps.storage, &cfg)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "leave")
+        .add_attribute("admin", info.sender))
+}
+
+pub fn execute_schedule(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msgs: Vec<CosmosMsg>,
+    salt: Binary,
+) -> Result<Response, ContractError> {
+    let cfg = ADMIN_LIST.load(deps.storage)?;
+    if !cfg.is_admin(info.sender.as_ref()) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let min_delay = MIN_DELAY.load(deps.storage)?.unwrap_or_default();
+    let eta = env.block.time.plus_seconds(min_delay);
+    let id = scheduled_op_id(&msgs, &salt)?;
+
+    //same msgs+salt hash to the same id - reject instead of silently overwriting whatever
+    //op/eta was already scheduled under it
+    if SCHEDULED_OPS.has(deps.storage, id) {
+        return Err(ContractError::AlreadyScheduled {});
+    }
+
+    let op = ScheduledOp { msgs, eta };
+    SCHEDULED_OPS.save(deps.storage, id, &op)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "schedule")
+        .add_attribute("id", id.to_string())
+        .add_attribute("eta", eta.seconds().to_string()))
+}
+
+pub fn execute_scheduled(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let cfg = ADMIN_LIST.load(deps.storage)?;
+    if !cfg.is_admin(info.sender.as_ref()) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let op = SCHEDULED_OPS.load(deps.storage, id)?;
+    if env.block.time < op.eta {
+        return Err(ContractError::TooEarly {});
+    }
+    SCHEDULED_OPS.remove(deps.storage, id);
+
+    Ok(Response::new()
+        .add_messages(op.msgs)
+        .add_attribute("action", "execute_scheduled")
+        .add_attribute("id", id.to_string()))
+}
+
+pub fn execute_cancel(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let cfg = ADMIN_LIST.load(deps.storage)?;
+    if !cfg.is_admin(info.sender.as_ref()) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    SCHEDULED_OPS.load(deps.storage, id)?; //make sure the id exists before we silently drop it
+    SCHEDULED_OPS.remove(deps.storage, id);
+
+    Ok(Response::new()
+        .add_attribute("action", "cancel")
+        .add_attribute("id", id.to_string()))
+}
+
+//hashes the serialized msgs together with the caller-supplied salt so the same batch can be
+//rescheduled under a different id just by changing the salt
+fn scheduled_op_id(msgs: &[CosmosMsg], salt: &Binary) -> StdResult<u64> {
+    let mut hasher = Sha256::new();
+    hasher.update(to_binary(msgs)?.as_slice());
+    hasher.update(salt.as_slice());
+    let digest = hasher.finalize();
+    let mut id_bytes = [0u8; 8];
+    id_bytes.copy_from_slice(&digest[..8]);
+    Ok(u64::from_be_bytes(id_bytes))
+}
+
+//anyone can call this with funds attached - it just splits whatever came in evenly across the
+//current admins, rewarding the privileged signers instead of leaving the funds idle
+pub fn execute_donate(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    let cfg = ADMIN_LIST.load(deps.storage)?;
+    if cfg.admins.is_empty() {
+        return Err(ContractError::NoAdmins {});
+    }
+    if info.funds.is_empty() {
+        return Err(ContractError::EmptyFunds {});
+    }
+
+    let num_admins = Uint128::from(cfg.admins.len() as u128);
+    let share: Vec<Coin> = info
+        .funds
+        .iter()
+        //integer division drops the remainder so the total paid out never exceeds what was sent
+        .filter_map(|c| {
+            let amount = c.amount / num_admins;
+            (!amount.is_zero()).then(|| Coin { denom: c.denom.clone(), amount })
+        })
+        .collect();
+
+    //a donation smaller than the admin count can round every denom down to zero - a
+    //BankMsg::Send with an empty amount fails bank-module validation, so skip sending
+    //anything rather than bricking the whole call over an unavoidable rounding no-op
+    if share.is_empty() {
+        return Ok(Response::new().add_attribute("action", "donate"));
+    }
+
+    let mut res = Response::new().add_attribute("action", "donate");
+    for admin in &cfg.admins {
+        res = res
+            .add_message(BankMsg::Send {
+                to_address: admin.to_string(),
+                amount: share.clone(),
+            })
+            .add_attribute("recipient", admin.to_string());
+    }
+    Ok(res)
+}
+
 fn can_execute(deps: Deps, sender: &str) -> StdResult<bool> {
     let cfg = ADMIN_LIST.load(deps.storage)?; //loading the adminlist from the state
     let can = cfg.is_admin(&sender);//checking whether the sender is in the adminlist or not by invoking 
@@ -122,9 +583,103 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {//query
     match msg {
         QueryMsg::AdminList {} => to_binary(&query_admin_list(deps)?), //returning the admin list by calling the query_admin_list method below
         QueryMsg::CanExecute { sender, msg } => to_binary(&query_can_execute(deps, sender, msg)?),
+        QueryMsg::Allowance { spender } => to_binary(&query_allowance(deps, spender)?),
+        QueryMsg::AllAllowances { start_after, limit } => {
+            to_binary(&query_all_allowances(deps, start_after, limit)?)
+        }
+        QueryMsg::Permissions { spender } => to_binary(&query_permissions(deps, spender)?),
+        QueryMsg::ScheduledOp { id } => to_binary(&query_scheduled_op(deps, id)?),
+        QueryMsg::ScheduledOps { start_after, limit } => {
+            to_binary(&query_scheduled_ops(deps, start_after, limit)?)
+        }
     }
 }
 
+pub fn query_scheduled_op(deps: Deps, id: u64) -> StdResult<ScheduledOpResponse> {
+    let op = SCHEDULED_OPS.load(deps.storage, id)?;
+    Ok(ScheduledOpResponse {
+        id,
+        msgs: op.msgs,
+        eta: op.eta,
+    })
+}
+
+pub fn query_scheduled_ops(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<ScheduledOpsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(cw_storage_plus::Bound::<u64>::exclusive);
+
+    let ops = SCHEDULED_OPS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (id, op) = item?;
+            Ok(ScheduledOpResponse {
+                id,
+                msgs: op.msgs,
+                eta: op.eta,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(ScheduledOpsResponse { ops })
+}
+
+pub fn query_permissions(deps: Deps, spender: String) -> StdResult<PermissionsResponse> {
+    let spender_addr = deps.api.addr_validate(&spender)?;
+    let permissions = PERMISSIONS
+        .may_load(deps.storage, &spender_addr)?
+        .unwrap_or_default();
+    Ok(PermissionsResponse {
+        delegate: permissions.delegate,
+        redelegate: permissions.redelegate,
+        undelegate: permissions.undelegate,
+        withdraw: permissions.withdraw,
+    })
+}
+
+pub fn query_allowance(deps: Deps, spender: String) -> StdResult<AllowanceResponse> {
+    let spender_addr = deps.api.addr_validate(&spender)?;
+    //no allowance on record reads the same as a zero balance, there's nothing to grant yet
+    let allowance = ALLOWANCES
+        .may_load(deps.storage, &spender_addr)?
+        .unwrap_or_default();
+    Ok(AllowanceResponse {
+        balance: allowance.balance,
+        expires: allowance.expires,
+    })
+}
+
+pub fn query_all_allowances(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<AllAllowancesResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after
+        .map(|s| deps.api.addr_validate(&s))
+        .transpose()?
+        .map(cw_storage_plus::Bound::exclusive);
+
+    let allowances = ALLOWANCES
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (spender, allowance) = item?;
+            Ok(AllowanceInfo {
+                spender: spender.into(),
+                balance: allowance.balance,
+                expires: allowance.expires,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(AllAllowancesResponse { allowances })
+}
+
 pub fn query_admin_list(deps: Deps) -> StdResult<AdminListResponse> {
     let cfg = ADMIN_LIST.load(deps.storage)?;// loading the adminlist 
     Ok(AdminListResponse {//returning the response with the adminlist and whether its mutable or not
@@ -164,6 +719,7 @@ mod tests {
         let instantiate_msg = InstantiateMsg {
             admins: vec![alice.to_string(), bob.to_string(), carl.to_string()],
             mutable: true,
+            min_delay: None,
         };
         let info = mock_info(anyone, &[]);
         instantiate(deps.as_mut(), mock_env(), info, instantiate_msg).unwrap();
@@ -232,6 +788,7 @@ mod tests {
         let instantiate_msg = InstantiateMsg {
             admins: vec![alice.to_string(), carl.to_string()],
             mutable: false,
+            min_delay: None,
         };
         let info = mock_info(bob, &[]);
         instantiate(deps.as_mut(), mock_env(), info, instantiate_msg).unwrap();
@@ -282,6 +839,7 @@ mod tests {
         let instantiate_msg = InstantiateMsg {
             admins: vec![alice.to_string(), bob.to_string()],
             mutable: false,
+            min_delay: None,
         };
         let info = mock_info(anyone, &[]);
         instantiate(deps.as_mut(), mock_env(), info, instantiate_msg).unwrap();
@@ -312,4 +870,594 @@ mod tests {
         let res = query_can_execute(deps.as_ref(), anyone.to_string(), staking_msg).unwrap();
         assert!(!res.can_execute);
     }
+
+    #[test]
+    fn subkey_can_spend_up_to_its_allowance() {
+        let mut deps = mock_dependencies();
+
+        let alice = "alice";
+        let bob = "bob"; // bob is a subkey, not an admin
+
+        let instantiate_msg = InstantiateMsg {
+            admins: vec![alice.to_string()],
+            mutable: true,
+            min_delay: None,
+        };
+        let info = mock_info(alice, &[]);
+        instantiate(deps.as_mut(), mock_env(), info, instantiate_msg).unwrap();
+
+        // bob has no allowance yet, so he cannot execute anything
+        let send = vec![CosmosMsg::Bank(BankMsg::Send {
+            to_address: "random".into(),
+            amount: coins(100, "ujuno"),
+        })];
+        let info = mock_info(bob, &[]);
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::Execute { msgs: send.clone() },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::NoAllowance {});
+
+        // alice grants bob a 100 ujuno allowance
+        let info = mock_info(alice, &[]);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::IncreaseAllowance {
+                spender: bob.to_string(),
+                amount: coin(100, "ujuno"),
+                expires: None,
+            },
+        )
+        .unwrap();
+
+        // bob can now spend exactly his allowance
+        let info = mock_info(bob, &[]);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::Execute { msgs: send },
+        )
+        .unwrap();
+
+        // the allowance is fully spent, so bob is back to square one
+        let info = mock_info(bob, &[]);
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::Execute {
+                msgs: vec![CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "random".into(),
+                    amount: coins(1, "ujuno"),
+                })],
+            },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::NoAllowance {});
+    }
+
+    #[test]
+    fn subkey_with_delegate_permission_cannot_send() {
+        let mut deps = mock_dependencies();
+
+        let alice = "alice";
+        let bob = "bob"; // bob gets a validator-management key
+
+        let instantiate_msg = InstantiateMsg {
+            admins: vec![alice.to_string()],
+            mutable: true,
+            min_delay: None,
+        };
+        let info = mock_info(alice, &[]);
+        instantiate(deps.as_mut(), mock_env(), info, instantiate_msg).unwrap();
+
+        let info = mock_info(alice, &[]);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::SetPermissions {
+                spender: bob.to_string(),
+                permissions: Permissions {
+                    delegate: true,
+                    redelegate: false,
+                    undelegate: false,
+                    withdraw: false,
+                },
+            },
+        )
+        .unwrap();
+
+        // bob can restake rewards...
+        let delegate_msg = vec![CosmosMsg::Staking(StakingMsg::Delegate {
+            validator: "validator".into(),
+            amount: coin(500, "ujuno"),
+        })];
+        let info = mock_info(bob, &[]);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::Execute { msgs: delegate_msg },
+        )
+        .unwrap();
+
+        // ...but cannot move funds out via a bank send, since he has no allowance either
+        let send_msg = vec![CosmosMsg::Bank(BankMsg::Send {
+            to_address: "random".into(),
+            amount: coins(1, "ujuno"),
+        })];
+        let info = mock_info(bob, &[]);
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::Execute { msgs: send_msg },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::NoAllowance {});
+
+        // and he cannot undelegate, since that permission was never granted
+        let undelegate_msg = vec![CosmosMsg::Staking(StakingMsg::Undelegate {
+            validator: "validator".into(),
+            amount: coin(500, "ujuno"),
+        })];
+        let info = mock_info(bob, &[]);
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::Execute { msgs: undelegate_msg },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::NotAllowed {});
+    }
+
+    #[test]
+    fn withdraw_permission_lets_harvest_rewards_but_not_redirect_them() {
+        let mut deps = mock_dependencies();
+
+        let alice = "alice";
+        let bob = "bob"; // bob gets a rewards-harvesting key
+
+        let instantiate_msg = InstantiateMsg {
+            admins: vec![alice.to_string()],
+            mutable: true,
+            min_delay: None,
+        };
+        let info = mock_info(alice, &[]);
+        instantiate(deps.as_mut(), mock_env(), info, instantiate_msg).unwrap();
+
+        let info = mock_info(alice, &[]);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::SetPermissions {
+                spender: bob.to_string(),
+                permissions: Permissions {
+                    delegate: false,
+                    redelegate: false,
+                    undelegate: false,
+                    withdraw: true,
+                },
+            },
+        )
+        .unwrap();
+
+        // bob can withdraw (harvest) delegator rewards...
+        let withdraw_msg = vec![CosmosMsg::Distribution(DistributionMsg::WithdrawDelegatorReward {
+            validator: "validator".into(),
+        })];
+        let info = mock_info(bob, &[]);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::Execute { msgs: withdraw_msg },
+        )
+        .unwrap();
+
+        // ...but `withdraw` alone must never let him redirect those future rewards to an
+        // address of his own choosing - that's moving funds out, not harvesting them
+        let redirect_msg = vec![CosmosMsg::Distribution(DistributionMsg::SetWithdrawAddress {
+            address: bob.to_string(),
+        })];
+        let info = mock_info(bob, &[]);
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::Execute { msgs: redirect_msg },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::NotAllowed {});
+    }
+
+    #[test]
+    fn scheduled_op_waits_out_the_min_delay() {
+        let mut deps = mock_dependencies();
+
+        let alice = "alice";
+
+        let instantiate_msg = InstantiateMsg {
+            admins: vec![alice.to_string()],
+            mutable: true,
+            min_delay: Some(100),
+        };
+        let info = mock_info(alice, &[]);
+        instantiate(deps.as_mut(), mock_env(), info, instantiate_msg).unwrap();
+
+        let msgs = vec![CosmosMsg::Bank(BankMsg::Send {
+            to_address: "random".into(),
+            amount: coins(10, "ujuno"),
+        })];
+        let info = mock_info(alice, &[]);
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::Schedule {
+                msgs: msgs.clone(),
+                salt: Binary::from(b"salt".as_slice()),
+            },
+        )
+        .unwrap();
+        let id: u64 = res
+            .attributes
+            .iter()
+            .find(|a| a.key == "id")
+            .unwrap()
+            .value
+            .parse()
+            .unwrap();
+
+        // too early - the min_delay has not elapsed yet
+        let info = mock_info(alice, &[]);
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::ExecuteScheduled { id },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::TooEarly {});
+
+        // once the delay has elapsed the op can be executed
+        let mut later_env = mock_env();
+        later_env.block.time = later_env.block.time.plus_seconds(101);
+        let info = mock_info(alice, &[]);
+        let res = execute(
+            deps.as_mut(),
+            later_env,
+            info,
+            ExecuteMsg::ExecuteScheduled { id },
+        )
+        .unwrap();
+        assert_eq!(
+            res.messages,
+            msgs.into_iter().map(SubMsg::new).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn min_delay_forces_admins_through_the_timelock() {
+        let mut deps = mock_dependencies();
+
+        let alice = "alice";
+
+        let instantiate_msg = InstantiateMsg {
+            admins: vec![alice.to_string()],
+            mutable: true,
+            min_delay: Some(100),
+        };
+        let info = mock_info(alice, &[]);
+        instantiate(deps.as_mut(), mock_env(), info, instantiate_msg).unwrap();
+
+        // even alice, a full admin, cannot call Execute directly once a min_delay is configured
+        let msgs = vec![CosmosMsg::Bank(BankMsg::Send {
+            to_address: "random".into(),
+            amount: coins(10, "ujuno"),
+        })];
+        let info = mock_info(alice, &[]);
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::Execute { msgs },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::TimelockRequired {});
+    }
+
+    #[test]
+    fn min_delay_blocks_allowance_grants_and_allowance_funded_execute() {
+        let mut deps = mock_dependencies();
+
+        let alice = "alice";
+        let bob = "bob";
+
+        let instantiate_msg = InstantiateMsg {
+            admins: vec![alice.to_string()],
+            mutable: true,
+            min_delay: Some(100),
+        };
+        let info = mock_info(alice, &[]);
+        instantiate(deps.as_mut(), mock_env(), info, instantiate_msg).unwrap();
+
+        // granting a fresh allowance is itself a privileged spend once a timelock is
+        // configured - alice can no longer set bob up to drain funds the instant she grants it
+        let info = mock_info(alice, &[]);
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::IncreaseAllowance {
+                spender: bob.to_string(),
+                amount: coin(100, "ujuno"),
+                expires: None,
+            },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::TimelockRequired {});
+
+        let info = mock_info(alice, &[]);
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::SetAllowance {
+                spender: bob.to_string(),
+                amount: coins(100, "ujuno"),
+                expires: None,
+            },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::TimelockRequired {});
+
+        // even a pre-existing allowance (granted before min_delay was configured, or just
+        // written straight into storage for the test) can no longer be spent directly
+        ALLOWANCES
+            .save(
+                deps.as_mut().storage,
+                &Addr::unchecked(bob),
+                &Allowance {
+                    balance: coins(100, "ujuno"),
+                    expires: Expiration::Never {},
+                },
+            )
+            .unwrap();
+        let msgs = vec![CosmosMsg::Bank(BankMsg::Send {
+            to_address: "random".into(),
+            amount: coins(10, "ujuno"),
+        })];
+        let info = mock_info(bob, &[]);
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::Execute { msgs },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::TimelockRequired {});
+    }
+
+    #[test]
+    fn rescheduling_the_same_msgs_and_salt_is_rejected() {
+        let mut deps = mock_dependencies();
+
+        let alice = "alice";
+
+        let instantiate_msg = InstantiateMsg {
+            admins: vec![alice.to_string()],
+            mutable: true,
+            min_delay: Some(100),
+        };
+        let info = mock_info(alice, &[]);
+        instantiate(deps.as_mut(), mock_env(), info, instantiate_msg).unwrap();
+
+        let msgs = vec![CosmosMsg::Bank(BankMsg::Send {
+            to_address: "random".into(),
+            amount: coins(10, "ujuno"),
+        })];
+        let schedule_msg = ExecuteMsg::Schedule {
+            msgs,
+            salt: Binary::from(b"salt".as_slice()),
+        };
+
+        let info = mock_info(alice, &[]);
+        execute(deps.as_mut(), mock_env(), info, schedule_msg.clone()).unwrap();
+
+        // same msgs + same salt hash to the same id - the second Schedule call must not
+        // silently clobber the first one's stored op/eta
+        let info = mock_info(alice, &[]);
+        let err = execute(deps.as_mut(), mock_env(), info, schedule_msg).unwrap_err();
+        assert_eq!(err, ContractError::AlreadyScheduled {});
+    }
+
+    #[test]
+    fn cancel_removes_a_scheduled_op_and_list_query_reflects_it() {
+        let mut deps = mock_dependencies();
+
+        let alice = "alice";
+
+        let instantiate_msg = InstantiateMsg {
+            admins: vec![alice.to_string()],
+            mutable: true,
+            min_delay: Some(100),
+        };
+        let info = mock_info(alice, &[]);
+        instantiate(deps.as_mut(), mock_env(), info, instantiate_msg).unwrap();
+
+        let msgs = vec![CosmosMsg::Bank(BankMsg::Send {
+            to_address: "random".into(),
+            amount: coins(10, "ujuno"),
+        })];
+        let info = mock_info(alice, &[]);
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::Schedule {
+                msgs,
+                salt: Binary::from(b"salt".as_slice()),
+            },
+        )
+        .unwrap();
+        let id: u64 = res
+            .attributes
+            .iter()
+            .find(|a| a.key == "id")
+            .unwrap()
+            .value
+            .parse()
+            .unwrap();
+
+        let listed = query_scheduled_ops(deps.as_ref(), None, None).unwrap();
+        assert_eq!(listed.ops.len(), 1);
+        assert_eq!(listed.ops[0].id, id);
+
+        // a non-admin cannot cancel
+        let info = mock_info("anyone", &[]);
+        let err = execute(deps.as_mut(), mock_env(), info, ExecuteMsg::Cancel { id }).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        let info = mock_info(alice, &[]);
+        execute(deps.as_mut(), mock_env(), info, ExecuteMsg::Cancel { id }).unwrap();
+
+        let listed = query_scheduled_ops(deps.as_ref(), None, None).unwrap();
+        assert!(listed.ops.is_empty());
+
+        // the op is gone, so executing it again fails
+        let info = mock_info(alice, &[]);
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::ExecuteScheduled { id },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Std(_)));
+    }
+
+    #[test]
+    fn donate_splits_funds_evenly_among_admins() {
+        let mut deps = mock_dependencies();
+
+        let alice = "alice";
+        let bob = "bob";
+        let carl = "carl";
+
+        let instantiate_msg = InstantiateMsg {
+            admins: vec![alice.to_string(), bob.to_string(), carl.to_string()],
+            mutable: true,
+            min_delay: None,
+        };
+        let info = mock_info(alice, &[]);
+        instantiate(deps.as_mut(), mock_env(), info, instantiate_msg).unwrap();
+
+        // 100 ujuno split 3 ways leaves a 1 ujuno remainder behind, rather than over-paying
+        let info = mock_info("donor", &coins(100, "ujuno"));
+        let res = execute(deps.as_mut(), mock_env(), info, ExecuteMsg::Donate {}).unwrap();
+        assert_eq!(
+            res.messages,
+            vec![alice, bob, carl]
+                .into_iter()
+                .map(|admin| SubMsg::new(BankMsg::Send {
+                    to_address: admin.to_string(),
+                    amount: coins(33, "ujuno"),
+                }))
+                .collect::<Vec<_>>()
+        );
+
+        // no funds attached is rejected outright
+        let info = mock_info("donor", &[]);
+        let err = execute(deps.as_mut(), mock_env(), info, ExecuteMsg::Donate {}).unwrap_err();
+        assert_eq!(err, ContractError::EmptyFunds {});
+    }
+
+    #[test]
+    fn donate_smaller_than_admin_count_is_a_harmless_no_op() {
+        let mut deps = mock_dependencies();
+
+        let alice = "alice";
+        let bob = "bob";
+        let carl = "carl";
+
+        let instantiate_msg = InstantiateMsg {
+            admins: vec![alice.to_string(), bob.to_string(), carl.to_string()],
+            mutable: true,
+            min_delay: None,
+        };
+        let info = mock_info(alice, &[]);
+        instantiate(deps.as_mut(), mock_env(), info, instantiate_msg).unwrap();
+
+        // 2 ujuno split 3 ways rounds every admin's share down to zero - that must not produce
+        // a BankMsg::Send with an empty amount, which the bank module would reject outright
+        let info = mock_info("donor", &coins(2, "ujuno"));
+        let res = execute(deps.as_mut(), mock_env(), info, ExecuteMsg::Donate {}).unwrap();
+        assert!(res.messages.is_empty());
+    }
+
+    #[test]
+    fn add_members_and_leave() {
+        let mut deps = mock_dependencies();
+
+        let alice = "alice";
+        let bob = "bob";
+        let carl = "carl";
+
+        let instantiate_msg = InstantiateMsg {
+            admins: vec![alice.to_string()],
+            mutable: true,
+            min_delay: None,
+        };
+        let info = mock_info(alice, &[]);
+        instantiate(deps.as_mut(), mock_env(), info, instantiate_msg).unwrap();
+
+        // alice can add bob and carl without re-sending the whole list
+        let info = mock_info(alice, &[]);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::AddMembers {
+                admins: vec![bob.to_string(), carl.to_string()],
+            },
+        )
+        .unwrap();
+        let expected = AdminListResponse {
+            admins: vec![alice.to_string(), bob.to_string(), carl.to_string()],
+            mutable: true,
+        };
+        assert_eq!(query_admin_list(deps.as_ref()).unwrap(), expected);
+
+        // a non-admin cannot leave
+        let info = mock_info("anyone", &[]);
+        let err = execute(deps.as_mut(), mock_env(), info, ExecuteMsg::Leave {}).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        // bob can remove himself without touching alice or carl
+        let info = mock_info(bob, &[]);
+        execute(deps.as_mut(), mock_env(), info, ExecuteMsg::Leave {}).unwrap();
+        let expected = AdminListResponse {
+            admins: vec![alice.to_string(), carl.to_string()],
+            mutable: true,
+        };
+        assert_eq!(query_admin_list(deps.as_ref()).unwrap(), expected);
+
+        // carl leaves too, leaving alice as the sole admin - she cannot leave and brick the contract
+        let info = mock_info(carl, &[]);
+        execute(deps.as_mut(), mock_env(), info, ExecuteMsg::Leave {}).unwrap();
+
+        let info = mock_info(alice, &[]);
+        let err = execute(deps.as_mut(), mock_env(), info, ExecuteMsg::Leave {}).unwrap_err();
+        assert_eq!(err, ContractError::NoAdmins {});
+    }
 }
\ No newline at end of file